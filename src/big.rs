@@ -0,0 +1,144 @@
+//! Arbitrary-precision primality testing, for candidates beyond `u64`.
+//!
+//! Gated behind the `bigint` feature so the default build stays
+//! dependency-free.
+
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::{One, Zero};
+
+/// The first 300 primes, used to cheaply reject most composites before
+/// falling back to the probabilistic Miller-Rabin test below.
+const SMALL_PRIMES: [u32; 300] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89,
+    97, 101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181, 191,
+    193, 197, 199, 211, 223, 227, 229, 233, 239, 241, 251, 257, 263, 269, 271, 277, 281, 283, 293,
+    307, 311, 313, 317, 331, 337, 347, 349, 353, 359, 367, 373, 379, 383, 389, 397, 401, 409, 419,
+    421, 431, 433, 439, 443, 449, 457, 461, 463, 467, 479, 487, 491, 499, 503, 509, 521, 523, 541,
+    547, 557, 563, 569, 571, 577, 587, 593, 599, 601, 607, 613, 617, 619, 631, 641, 643, 647, 653,
+    659, 661, 673, 677, 683, 691, 701, 709, 719, 727, 733, 739, 743, 751, 757, 761, 769, 773, 787,
+    797, 809, 811, 821, 823, 827, 829, 839, 853, 857, 859, 863, 877, 881, 883, 887, 907, 911, 919,
+    929, 937, 941, 947, 953, 967, 971, 977, 983, 991, 997, 1009, 1013, 1019, 1021, 1031, 1033,
+    1039, 1049, 1051, 1061, 1063, 1069, 1087, 1091, 1093, 1097, 1103, 1109, 1117, 1123, 1129,
+    1151, 1153, 1163, 1171, 1181, 1187, 1193, 1201, 1213, 1217, 1223, 1229, 1231, 1237, 1249,
+    1259, 1277, 1279, 1283, 1289, 1291, 1297, 1301, 1303, 1307, 1319, 1321, 1327, 1361, 1367,
+    1373, 1381, 1399, 1409, 1423, 1427, 1429, 1433, 1439, 1447, 1451, 1453, 1459, 1471, 1481,
+    1483, 1487, 1489, 1493, 1499, 1511, 1523, 1531, 1543, 1549, 1553, 1559, 1567, 1571, 1579,
+    1583, 1597, 1601, 1607, 1609, 1613, 1619, 1621, 1627, 1637, 1657, 1663, 1667, 1669, 1693,
+    1697, 1699, 1709, 1721, 1723, 1733, 1741, 1747, 1753, 1759, 1777, 1783, 1787, 1789, 1801,
+    1811, 1823, 1831, 1847, 1861, 1867, 1871, 1873, 1877, 1879, 1889, 1901, 1907, 1913, 1931,
+    1933, 1949, 1951, 1973, 1979, 1987,
+];
+
+/// The default number of Miller-Rabin rounds used by [`is_prime_big`].
+///
+/// Each round cuts the false-positive probability for a composite input by
+/// at least a factor of 4, so 40 rounds gives a probability of at most
+/// `4^-40 = 2^-80`.
+const DEFAULT_ROUNDS: u32 = 40;
+
+/// Probabilistic Miller-Rabin primality test over arbitrary-precision
+/// integers, checking `rounds` independent random bases.
+///
+/// Trial division against the first 300 primes runs first to cheaply
+/// reject most composites; `rounds` only governs the Miller-Rabin rounds
+/// that follow, so callers can trade speed against false-positive
+/// probability. A composite `n` is correctly rejected with probability at
+/// least `1 - 4^-rounds`; a prime `n` is always accepted.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::BigUint;
+/// assert!(yapl::is_probably_prime_big(&BigUint::from(104729u32), 20));
+/// assert!(!yapl::is_probably_prime_big(&BigUint::from(104730u32), 20));
+/// ```
+pub fn is_probably_prime_big(n: &BigUint, rounds: u32) -> bool {
+    let zero = BigUint::zero();
+    let one = BigUint::one();
+    let two = &one + &one;
+
+    if *n < two {
+        return false;
+    }
+    if *n == two {
+        return true;
+    }
+    if (n % &two) == zero {
+        return false;
+    }
+
+    for &p in SMALL_PRIMES.iter() {
+        let p = BigUint::from(p);
+        if *n == p {
+            return true;
+        }
+        if (n % &p) == zero {
+            return false;
+        }
+    }
+
+    let n_minus_one = n - &one;
+    let mut d = n_minus_one.clone();
+    let mut s = 0u32;
+    while (&d % &two) == zero {
+        d /= &two;
+        s += 1;
+    }
+
+    let mut rng = rand::thread_rng();
+    'rounds: for _ in 0..rounds {
+        let a = rng.gen_biguint_range(&two, &n_minus_one);
+        let mut x = a.modpow(&d, n);
+        if x == one || x == n_minus_one {
+            continue;
+        }
+        for _ in 0..s - 1 {
+            x = (&x * &x) % n;
+            if x == n_minus_one {
+                continue 'rounds;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// Checks whether the arbitrary-precision integer `n` is (probably) prime,
+/// using [`DEFAULT_ROUNDS`] rounds of Miller-Rabin.
+///
+/// This is the `bigint`-feature counterpart to [`crate::is_prime`], for
+/// candidates beyond the `u64` range.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::BigUint;
+/// assert!(yapl::is_prime_big(&BigUint::from(104729u32)));
+/// assert!(!yapl::is_prime_big(&BigUint::from(104730u32)));
+/// ```
+pub fn is_prime_big(n: &BigUint) -> bool {
+    is_probably_prime_big(n, DEFAULT_ROUNDS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_prime_big_small_cases() {
+        for n in [0u32, 1, 4, 6, 8, 9, 10].iter() {
+            assert!(!is_prime_big(&BigUint::from(*n)));
+        }
+        for n in [2u32, 3, 5, 7, 11, 13, 104729].iter() {
+            assert!(is_prime_big(&BigUint::from(*n)));
+        }
+    }
+
+    #[test]
+    fn test_is_prime_big_beyond_u64() {
+        // 2^127 - 1, a Mersenne prime well beyond u64::MAX.
+        let n = (BigUint::from(1u32) << 127) - BigUint::one();
+        assert!(is_prime_big(&n));
+        assert!(!is_prime_big(&(&n - BigUint::from(2u32))));
+    }
+}
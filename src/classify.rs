@@ -0,0 +1,76 @@
+//! Classifying primes by how they sit relative to their neighbors.
+
+use crate::{is_prime, next_prime, prev_prime};
+
+/// How a prime `p` compares to the midpoint of its neighboring primes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrimeClass {
+    /// `p` is greater than the average of the previous and next prime.
+    Strong,
+    /// `p` is less than the average of the previous and next prime.
+    Weak,
+    /// `p` is exactly the average of the previous and next prime.
+    Balanced,
+}
+
+/// Classifies the prime `p` as [`PrimeClass::Strong`], [`PrimeClass::Weak`],
+/// or [`PrimeClass::Balanced`], based on its neighboring primes `q`
+/// (predecessor) and `r` (successor): strong when `p > (q + r) / 2`, weak
+/// when `p < (q + r) / 2`, balanced when equal.
+///
+/// The comparison is done as `2*p` vs `q + r` (widened to `u128`) to avoid
+/// both integer division and overflow.
+///
+/// Returns `None` if `p` is not prime, and for `2`, which has no prime
+/// predecessor.
+///
+/// # Examples
+///
+/// ```
+/// use yapl::PrimeClass;
+/// assert_eq!(yapl::classify(11), Some(PrimeClass::Strong));   // neighbors 7, 13
+/// assert_eq!(yapl::classify(5), Some(PrimeClass::Balanced));  // neighbors 3, 7
+/// assert_eq!(yapl::classify(7), Some(PrimeClass::Weak));      // neighbors 5, 11
+/// assert_eq!(yapl::classify(2), None);
+/// assert_eq!(yapl::classify(4), None);
+/// ```
+pub fn classify(p: u64) -> Option<PrimeClass> {
+    if p == 2 || !is_prime(p) {
+        return None;
+    }
+
+    let q = prev_prime(p)?;
+    let r = next_prime(p)?;
+
+    let twice_p = 2 * p as u128;
+    let sum_neighbors = q as u128 + r as u128;
+
+    Some(if twice_p > sum_neighbors {
+        PrimeClass::Strong
+    } else if twice_p < sum_neighbors {
+        PrimeClass::Weak
+    } else {
+        PrimeClass::Balanced
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_known_primes() {
+        assert_eq!(classify(3), Some(PrimeClass::Weak)); // neighbors 2, 5
+        assert_eq!(classify(5), Some(PrimeClass::Balanced)); // neighbors 3, 7
+        assert_eq!(classify(7), Some(PrimeClass::Weak)); // neighbors 5, 11
+        assert_eq!(classify(11), Some(PrimeClass::Strong)); // neighbors 7, 13
+    }
+
+    #[test]
+    fn test_classify_rejects_non_primes_and_two() {
+        assert_eq!(classify(2), None);
+        assert_eq!(classify(4), None);
+        assert_eq!(classify(0), None);
+        assert_eq!(classify(1), None);
+    }
+}
@@ -0,0 +1,201 @@
+//! Integer factorization.
+
+use crate::mulmod;
+
+/// Small primes used to cheaply strip small factors before reaching for
+/// Pollard's rho.
+const SMALL_FACTOR_PRIMES: [u64; 15] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47,
+];
+
+/// Returns the greatest common divisor of `a` and `b`.
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let t = b;
+        b = a % b;
+        a = t;
+    }
+    a
+}
+
+/// Finds a nontrivial factor of the composite `n` using Pollard's rho with
+/// the polynomial `f(x) = x^2 + c mod n`, given a starting constant `c`.
+///
+/// Returns `n` itself if this `c` fails to find a factor, so the caller can
+/// retry with a different constant.
+fn pollard_rho_attempt(n: u64, c: u64) -> u64 {
+    if n.is_multiple_of(2) {
+        return 2;
+    }
+
+    let f = |x: u64| (mulmod(x, x, n) + c) % n;
+
+    // Brent's cycle detection: advance y in batches of up to BATCH steps,
+    // accumulating the product of |x - y| differences and taking a single
+    // gcd per batch instead of one per step.
+    const BATCH: u64 = 128;
+
+    let mut x = 2u64;
+    let mut y = 2u64;
+    let mut ys = y;
+    let mut d = 1u64;
+    let mut q = 1u64;
+    let mut r = 1u64;
+
+    while d == 1 {
+        x = y;
+        for _ in 0..r {
+            y = f(y);
+        }
+
+        let mut k = 0;
+        while k < r && d == 1 {
+            ys = y;
+            let steps = BATCH.min(r - k);
+            for _ in 0..steps {
+                y = f(y);
+                q = mulmod(q, x.abs_diff(y), n);
+            }
+            d = gcd(q, n);
+            k += steps;
+        }
+        r *= 2;
+    }
+
+    if d == n {
+        // The batched product collapsed to 0 mod n, masking the step at
+        // which the cycle actually collided. Fall back to single steps
+        // from the last checkpoint to find it.
+        loop {
+            ys = f(ys);
+            d = gcd(x.abs_diff(ys), n);
+            if d > 1 {
+                break;
+            }
+        }
+    }
+
+    d
+}
+
+/// Maximum number of distinct constants `c` to try before giving up on
+/// splitting `n`. A genuine composite `u64` splits within a handful of
+/// attempts in practice; this bound only exists so that a cofactor wrongly
+/// deemed composite (e.g. an `is_prime` regression) is recorded as a prime
+/// factor instead of spinning forever.
+const MAX_POLLARD_ATTEMPTS: u64 = 100;
+
+/// Attempts to find a nontrivial factor of the composite `n`, trying up to
+/// [`MAX_POLLARD_ATTEMPTS`] constants before giving up.
+fn pollard_rho(n: u64) -> Option<u64> {
+    for c in 1..=MAX_POLLARD_ATTEMPTS {
+        let d = pollard_rho_attempt(n, c);
+        if d != n {
+            return Some(d);
+        }
+    }
+    None
+}
+
+/// Records one more occurrence of the prime factor `p` in `factors`.
+fn record_factor(factors: &mut Vec<(u64, u32)>, p: u64) {
+    match factors.iter_mut().find(|(factor, _)| *factor == p) {
+        Some((_, exponent)) => *exponent += 1,
+        None => factors.push((p, 1)),
+    }
+}
+
+/// Recursively splits the composite cofactor `n` into prime factors.
+fn factor_cofactor(n: u64, factors: &mut Vec<(u64, u32)>) {
+    if n == 1 {
+        return;
+    }
+    if crate::is_prime(n) {
+        record_factor(factors, n);
+        return;
+    }
+    match pollard_rho(n) {
+        Some(d) => {
+            factor_cofactor(d, factors);
+            factor_cofactor(n / d, factors);
+        }
+        // Pollard's rho couldn't split n: it must actually be prime despite
+        // is_prime's verdict. Record it as-is rather than looping forever.
+        None => record_factor(factors, n),
+    }
+}
+
+/// Returns the prime factorization of `n` as `(prime, exponent)` pairs,
+/// ordered by increasing prime.
+///
+/// `0` and `1` have no prime factors, so both return an empty `Vec`.
+///
+/// Small factors are stripped by trial division; the remaining cofactor (if
+/// any) is split with Pollard's rho, using [`crate::is_prime`] to recognize
+/// when a cofactor is already prime and recursion can stop.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(yapl::factorize(1), vec![]);
+/// assert_eq!(yapl::factorize(12), vec![(2, 2), (3, 1)]);
+/// assert_eq!(yapl::factorize(97), vec![(97, 1)]);
+/// ```
+pub fn factorize(n: u64) -> Vec<(u64, u32)> {
+    let mut factors = Vec::new();
+    if n <= 1 {
+        return factors;
+    }
+
+    let mut remaining = n;
+    for &p in SMALL_FACTOR_PRIMES.iter() {
+        if p * p > remaining {
+            break;
+        }
+        if remaining.is_multiple_of(p) {
+            let mut exponent = 0;
+            while remaining.is_multiple_of(p) {
+                remaining /= p;
+                exponent += 1;
+            }
+            factors.push((p, exponent));
+        }
+    }
+
+    if remaining > 1 {
+        factor_cofactor(remaining, &mut factors);
+    }
+
+    factors.sort_unstable();
+    factors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_factorize_small_cases() {
+        assert_eq!(factorize(0), vec![]);
+        assert_eq!(factorize(1), vec![]);
+        assert_eq!(factorize(2), vec![(2, 1)]);
+        assert_eq!(factorize(12), vec![(2, 2), (3, 1)]);
+        assert_eq!(factorize(97), vec![(97, 1)]);
+        assert_eq!(factorize(8633), vec![(89, 1), (97, 1)]);
+    }
+
+    #[test]
+    fn test_factorize_large_semiprime() {
+        // Two large primes, requiring Pollard's rho rather than trial division.
+        let factors = factorize(999999937 * 1000000007);
+        assert_eq!(factors, vec![(999999937, 1), (1000000007, 1)]);
+    }
+
+    #[test]
+    fn test_factorize_reconstructs_n() {
+        for n in [600851475143u64, 4294967295, 12345678910111] {
+            let product: u64 = factorize(n).iter().map(|&(p, e)| p.pow(e)).product();
+            assert_eq!(product, n);
+        }
+    }
+}
@@ -3,12 +3,91 @@
 //! `yapl` (Yet Another Prime Library) is a collection of commands to test and
 //! get prime numbers.
 
+#[cfg(feature = "bigint")]
+mod big;
+mod classify;
+mod factor;
+mod generate;
+mod montgomery;
+mod navigate;
+mod sieve;
+
+use montgomery::Montgomery;
+
+#[cfg(feature = "bigint")]
+pub use big::{is_prime_big, is_probably_prime_big};
+pub use classify::{classify, PrimeClass};
+pub use factor::factorize;
+pub use generate::{generate_prime, generate_safe_prime};
+pub use navigate::{next_prime, prev_prime};
+pub use sieve::Sieve;
+
+/// Computes `a * b mod m`, avoiding `u64` overflow by widening to `u128`.
+pub(crate) fn mulmod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+/// The first few primes, used to cheaply reject most composites before
+/// falling back to the full Miller-Rabin test.
+const SMALL_PRIMES: [u64; 11] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31];
+
+/// Witnesses for the deterministic Miller-Rabin test.
+///
+/// This fixed set of bases is known to correctly decide primality for every
+/// `n < 2^64`. See <https://miller-rabin.appspot.com/> for the derivation.
+const MILLER_RABIN_WITNESSES: [u64; 7] = [2, 325, 9375, 28178, 450775, 9780504, 1795265022];
+
+/// Deterministic Miller-Rabin primality test for `n`.
+///
+/// Assumes `n` is odd and greater than 2. Each witness is reduced mod `n`,
+/// and a witness that is a multiple of `n` is skipped, so `n` may be smaller
+/// than the witnesses themselves.
+///
+/// The modular exponentiations run in Montgomery form (see [`Montgomery`]),
+/// which avoids a `%` operation on every squaring and is noticeably faster
+/// than reducing with [`mulmod`] directly for the large `n` this test is
+/// meant for.
+fn miller_rabin(n: u64) -> bool {
+    let mut d = n - 1;
+    let mut s = 0u32;
+    while d.is_multiple_of(2) {
+        d /= 2;
+        s += 1;
+    }
+
+    let mont = Montgomery::new(n);
+    let one = mont.one();
+    let n_minus_one = mont.to_mont(n - 1);
+
+    'witnesses: for &a in MILLER_RABIN_WITNESSES.iter() {
+        if a % n == 0 {
+            continue;
+        }
+        let mut x = mont.pow(mont.to_mont(a % n), d);
+        if x == one || x == n_minus_one {
+            continue;
+        }
+        for _ in 0..s - 1 {
+            x = mont.mrmul(x, x);
+            if x == n_minus_one {
+                continue 'witnesses;
+            }
+        }
+        return false;
+    }
+    true
+}
+
 /// Checks if a given number is prime.
 ///
 /// A prime number is a natural number greater than 1 that has no positive divisors
 /// other than 1 and itself. This function returns `true` if the given number `n`
 /// is prime, and `false` otherwise.
 ///
+/// Primality is decided with a deterministic Miller-Rabin test, which is
+/// provably correct for the full `u64` range and runs in `O(log n)`
+/// multiplications rather than the `O(sqrt(n))` of trial division.
+///
 /// # Arguments
 ///
 /// * `n` - An unsigned 64-bit integer to be checked for primality.
@@ -27,31 +106,36 @@
 /// assert!(yapl::is_prime(104729));
 /// assert!(!yapl::is_prime(104730));
 /// ```
-
 pub fn is_prime(n: u64) -> bool {
     if n <= 1 {
         return false;
-    } else if n <= 3 {
-        return true;
-    } else if n % 2 == 0 || n % 3 == 0 {
-        return false;
     }
 
-    let mut i = 5;
-    while i * i <= n {
-        if n % i == 0 || n % (i + 2) == 0 {
+    for &p in SMALL_PRIMES.iter() {
+        if n == p {
+            return true;
+        }
+        if n.is_multiple_of(p) {
             return false;
         }
-        i += 6;
     }
-    return true;
+
+    miller_rabin(n)
 }
 
+/// Width, in numbers, of each window swept by [`PrimeIterator`]'s segmented sieve.
+const SEGMENT_SIZE: u64 = 1 << 16;
+
 /// An iterator that yields prime numbers in ascending order.
 ///
 /// The `PrimeIterator` struct provides an iterator over prime numbers,
 /// starting from the smallest prime number, which is 2.
 ///
+/// Internally it runs a segmented Sieve of Eratosthenes: rather than testing
+/// each candidate independently, it sieves one fixed-size window at a time
+/// against the "base" primes found so far, which keeps memory use bounded
+/// while streaming primes far beyond what per-candidate testing could reach.
+///
 /// # Examples
 ///
 /// ```
@@ -63,7 +147,16 @@ pub fn is_prime(n: u64) -> bool {
 /// ```
 #[derive(Debug)]
 pub struct PrimeIterator {
-    number: u64,
+    /// Primes discovered so far, used to sieve later segments.
+    base_primes: Vec<u64>,
+    /// Inclusive lower bound of the current segment.
+    low: u64,
+    /// Exclusive upper bound of the current segment.
+    high: u64,
+    /// `window[i]` is `true` if `low + i` is known composite.
+    window: Vec<bool>,
+    /// Index of the next candidate in `window` to inspect.
+    idx: usize,
 }
 
 impl PrimeIterator {
@@ -78,7 +171,69 @@ impl PrimeIterator {
     /// assert_eq!(primes.next(), Some(2));
     /// ```
     pub fn new() -> PrimeIterator {
-        PrimeIterator { number: 1 }
+        PrimeIterator {
+            base_primes: Vec::new(),
+            low: 0,
+            high: 0,
+            window: Vec::new(),
+            idx: 0,
+        }
+    }
+
+    /// Extends `base_primes` with every prime up to (and including) `bound`.
+    ///
+    /// Candidates are trial-divided against the primes already known; this
+    /// only ever runs over the small numbers up to `sqrt(high)`, so the
+    /// naive approach is cheap relative to sieving the segment itself.
+    fn extend_base_primes(&mut self, bound: u64) {
+        let mut candidate = self.base_primes.last().copied().unwrap_or(1) + 1;
+        while candidate <= bound {
+            let is_prime = self
+                .base_primes
+                .iter()
+                .take_while(|&&p| p * p <= candidate)
+                .all(|&p| candidate % p != 0);
+            if is_prime {
+                self.base_primes.push(candidate);
+            }
+            candidate += 1;
+        }
+    }
+
+    /// Advances to and sieves the next segment.
+    fn advance_segment(&mut self) {
+        self.low = self.high;
+        self.high = self.low + SEGMENT_SIZE;
+
+        let bound = (self.high as f64).sqrt() as u64 + 1;
+        self.extend_base_primes(bound);
+
+        let mut window = vec![false; SEGMENT_SIZE as usize];
+        if self.low == 0 {
+            for slot in window.iter_mut().take(2) {
+                *slot = true;
+            }
+        }
+        for &p in &self.base_primes {
+            if p * p >= self.high {
+                break;
+            }
+            let start = std::cmp::max(p * p, self.low.div_ceil(p) * p);
+            let mut multiple = start;
+            while multiple < self.high {
+                window[(multiple - self.low) as usize] = true;
+                multiple += p;
+            }
+        }
+
+        self.window = window;
+        self.idx = 0;
+    }
+}
+
+impl Default for PrimeIterator {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -99,13 +254,17 @@ impl Iterator for PrimeIterator {
     /// ```
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            self.number += 1;
-            print!("\t{}", self.number);
-            if is_prime(self.number) {
-                print!("<-\n");
-                return Some(self.number);
+            if self.idx >= self.window.len() {
+                self.advance_segment();
+            }
+            while self.idx < self.window.len() {
+                let candidate = self.low + self.idx as u64;
+                let composite = self.window[self.idx];
+                self.idx += 1;
+                if !composite {
+                    return Some(candidate);
+                }
             }
-            println!();
         }
     }
 }
@@ -216,11 +375,11 @@ mod tests {
         ];
 
         for n in prime_cases {
-            assert_eq!(is_prime(n), true);
+            assert!(is_prime(n));
         }
 
         for n in non_prime_cases {
-            assert_eq!(is_prime(n), false);
+            assert!(!is_prime(n));
         }
     }
 
@@ -237,4 +396,13 @@ mod tests {
         let prime = primer.nth(9999).unwrap();
         assert_eq!(prime, 104729);
     }
+
+    #[test]
+    fn test_is_prime_large_values() {
+        // 64-bit primes, well beyond the reach of trial division up to sqrt(n).
+        assert!(is_prime(18446744073709551557));
+        assert!(is_prime(1000000000000000003));
+        assert!(!is_prime(18446744073709551615));
+        assert!(!is_prime(1000000000000000000));
+    }
 }
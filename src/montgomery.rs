@@ -0,0 +1,99 @@
+//! Montgomery modular multiplication.
+//!
+//! Montgomery form lets repeated modular multiplication (as used by the
+//! Miller-Rabin exponentiations in [`crate::is_prime`]) avoid the relatively
+//! expensive `%` operation in favor of shifts, additions, and two extra
+//! multiplications per step (the REDC reduction).
+
+/// Precomputed constants for Montgomery multiplication modulo an odd `n`.
+pub(crate) struct Montgomery {
+    n: u64,
+    /// `-n^-1 mod 2^64`, used by the REDC reduction.
+    ni: u64,
+    /// `2^64 mod n`, i.e. the Montgomery representation of 1.
+    r: u64,
+    /// `2^128 mod n`, used to convert values into Montgomery form.
+    r2: u64,
+}
+
+impl Montgomery {
+    /// Builds the Montgomery context for modulus `n`.
+    ///
+    /// `n` must be odd, since Montgomery form requires `n` and `2^64` to be
+    /// coprime.
+    pub(crate) fn new(n: u64) -> Self {
+        debug_assert!(n % 2 == 1, "Montgomery modulus must be odd");
+
+        // Newton's method for the inverse of n modulo 2^64: each iteration
+        // doubles the number of correct low bits, so five rounds are enough
+        // to converge over all 64 bits. This converges to the positive
+        // inverse (n * ni == 1 mod 2^64); negate it to get the `-n^-1` that
+        // redc() actually needs.
+        let mut ni = n;
+        for _ in 0..5 {
+            ni = ni.wrapping_mul(2u64.wrapping_sub(n.wrapping_mul(ni)));
+        }
+        let ni = ni.wrapping_neg();
+
+        let r = ((1u128 << 64) % n as u128) as u64;
+        let r2 = ((r as u128 * r as u128) % n as u128) as u64;
+
+        Montgomery { n, ni, r, r2 }
+    }
+
+    /// REDC reduction: given `t < n * 2^64`, returns `t * 2^-64 mod n`.
+    ///
+    /// Computed as `(t_hi + mn_hi + carry)` rather than the more obvious
+    /// `(t + m * n) >> 64`: the latter can need up to 129 bits when `n` is
+    /// close to `u64::MAX`, overflowing `u128`. Splitting `t` and `m * n`
+    /// into high/low halves first keeps every intermediate within 65 bits,
+    /// since `t`'s low half is known to cancel against `m * n`'s low half
+    /// and contributes at most a single carry bit into the high half.
+    fn redc(&self, t: u128) -> u64 {
+        let t_lo = t as u64;
+        let t_hi = (t >> 64) as u64;
+
+        let m = t_lo.wrapping_mul(self.ni);
+        let mn = m as u128 * self.n as u128;
+        let mn_hi = (mn >> 64) as u64;
+        let carry = u64::from(t_lo != 0);
+
+        let sum_hi = t_hi as u128 + mn_hi as u128 + carry as u128;
+        if sum_hi >= self.n as u128 {
+            (sum_hi - self.n as u128) as u64
+        } else {
+            sum_hi as u64
+        }
+    }
+
+    /// Converts `a` (an ordinary residue mod `n`) into Montgomery form.
+    pub(crate) fn to_mont(&self, a: u64) -> u64 {
+        self.mrmul(a, self.r2)
+    }
+
+    /// Multiplies two Montgomery-form residues, returning `a * b * r^-1 mod n`
+    /// (which is itself a Montgomery-form residue).
+    pub(crate) fn mrmul(&self, a: u64, b: u64) -> u64 {
+        self.redc(a as u128 * b as u128)
+    }
+
+    /// The Montgomery representation of 1, i.e. `2^64 mod n`.
+    pub(crate) fn one(&self) -> u64 {
+        self.r
+    }
+
+    /// Raises the Montgomery-form residue `base` to `exp`, returning the
+    /// result in Montgomery form.
+    pub(crate) fn pow(&self, base: u64, mut exp: u64) -> u64 {
+        let mut result = self.one();
+        let mut base = base;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = self.mrmul(result, base);
+            }
+            base = self.mrmul(base, base);
+            exp >>= 1;
+        }
+        result
+    }
+}
@@ -0,0 +1,165 @@
+//! Navigating to the nearest prime from an arbitrary starting point.
+
+use crate::is_prime;
+
+/// Residues in `1..210` coprime to `2*3*5*7 = 210`, ascending. Any number
+/// coprime to 210 is congruent to one of these.
+const WHEEL_RESIDUES: [u64; 48] = [
+    1, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97, 101,
+    103, 107, 109, 113, 121, 127, 131, 137, 139, 143, 149, 151, 157, 163, 167, 169, 173, 179, 181,
+    187, 191, 193, 197, 199, 209,
+];
+
+/// Gaps between consecutive [`WHEEL_RESIDUES`], wrapping: `WHEEL_GAPS[i]` is
+/// the distance from `WHEEL_RESIDUES[i]` to the next coprime residue (the
+/// last entry wraps around to `WHEEL_RESIDUES[0]` of the following cycle).
+const WHEEL_GAPS: [u64; 48] = [
+    10, 2, 4, 2, 4, 6, 2, 6, 4, 2, 4, 6, 6, 2, 6, 4, 2, 6, 4, 6, 8, 4, 2, 4, 2, 4, 8, 6, 4, 6, 2,
+    4, 6, 2, 6, 6, 4, 2, 4, 6, 2, 6, 4, 2, 4, 2, 10, 2,
+];
+
+/// Smallest number `>= v` that is coprime to 210, and its index in
+/// [`WHEEL_RESIDUES`]. Assumes `v >= 11`.
+fn align_ascending(v: u64) -> (u64, usize) {
+    let cycle = v / 210;
+    let rem = v % 210;
+    match WHEEL_RESIDUES.iter().position(|&r| r >= rem) {
+        Some(idx) => (cycle * 210 + WHEEL_RESIDUES[idx], idx),
+        None => (cycle * 210 + 210 + WHEEL_RESIDUES[0], 0),
+    }
+}
+
+/// Largest number `<= v` that is coprime to 210, and its index in
+/// [`WHEEL_RESIDUES`]. Assumes `v >= 11`.
+fn align_descending(v: u64) -> (u64, usize) {
+    let cycle = v / 210;
+    let rem = v % 210;
+    match WHEEL_RESIDUES.iter().rposition(|&r| r <= rem) {
+        Some(idx) => (cycle * 210 + WHEEL_RESIDUES[idx], idx),
+        None => {
+            // rem == 0, so v is a multiple of 210 and thus v >= 210: the
+            // previous cycle's last residue is the answer.
+            let idx = WHEEL_RESIDUES.len() - 1;
+            ((cycle - 1) * 210 + WHEEL_RESIDUES[idx], idx)
+        }
+    }
+}
+
+/// Returns the smallest prime strictly greater than `n`.
+///
+/// Candidates below 11 (the first prime above the wheel's base primes 2, 3,
+/// 5, 7) are checked directly; from 11 on, candidates are generated by walking a wheel over
+/// the residues coprime to `2*3*5*7 = 210`, which skips roughly 77% of
+/// composites for free before each survivor is confirmed with
+/// [`crate::is_prime`].
+///
+/// Returns `None` if there is no prime representable in `u64` greater than
+/// `n` (i.e. `n >= ` the largest `u64` prime).
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(yapl::next_prime(10), Some(11));
+/// assert_eq!(yapl::next_prime(11), Some(13));
+/// ```
+pub fn next_prime(n: u64) -> Option<u64> {
+    if n < 2 {
+        return Some(2);
+    }
+    // 18446744073709551557 is the largest prime that fits in `u64`. Stopping
+    // here also keeps `n + 1` and the wheel alignment below from overflowing.
+    if n >= 18_446_744_073_709_551_557 {
+        return None;
+    }
+
+    let mut candidate = n + 1;
+    while candidate < 11 {
+        if is_prime(candidate) {
+            return Some(candidate);
+        }
+        candidate += 1;
+    }
+
+    let (mut candidate, mut idx) = align_ascending(candidate);
+    loop {
+        if is_prime(candidate) {
+            return Some(candidate);
+        }
+        candidate = candidate.checked_add(WHEEL_GAPS[idx])?;
+        idx = (idx + 1) % WHEEL_GAPS.len();
+    }
+}
+
+/// Returns the largest prime strictly less than `n`.
+///
+/// Mirrors [`next_prime`], walking the same 210-wheel downward.
+///
+/// Returns `None` if `n <= 2`, since there is no prime below 2.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(yapl::prev_prime(11), Some(7));
+/// assert_eq!(yapl::prev_prime(3), Some(2));
+/// assert_eq!(yapl::prev_prime(2), None);
+/// ```
+pub fn prev_prime(n: u64) -> Option<u64> {
+    if n <= 2 {
+        return None;
+    }
+
+    let mut candidate = n - 1;
+    while candidate < 11 {
+        if is_prime(candidate) {
+            return Some(candidate);
+        }
+        candidate -= 1;
+    }
+
+    let (mut candidate, mut idx) = align_descending(candidate);
+    loop {
+        if is_prime(candidate) {
+            return Some(candidate);
+        }
+        idx = (idx + WHEEL_GAPS.len() - 1) % WHEEL_GAPS.len();
+        candidate = candidate.checked_sub(WHEEL_GAPS[idx])?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_prime() {
+        assert_eq!(next_prime(0), Some(2));
+        assert_eq!(next_prime(1), Some(2));
+        assert_eq!(next_prime(2), Some(3));
+        assert_eq!(next_prime(7), Some(11));
+        assert_eq!(next_prime(8), Some(11));
+        assert_eq!(next_prime(10), Some(11));
+        assert_eq!(next_prime(11), Some(13));
+        assert_eq!(next_prime(199), Some(211));
+        assert_eq!(next_prime(104729), Some(104743));
+    }
+
+    #[test]
+    fn test_prev_prime() {
+        assert_eq!(prev_prime(2), None);
+        assert_eq!(prev_prime(3), Some(2));
+        assert_eq!(prev_prime(4), Some(3));
+        assert_eq!(prev_prime(11), Some(7));
+        assert_eq!(prev_prime(210), Some(199));
+        assert_eq!(prev_prime(104729), Some(104723));
+    }
+
+    #[test]
+    fn test_next_and_prev_are_consistent() {
+        let primes: Vec<u64> = crate::PrimeIterator::new().take(200).collect();
+        for window in primes.windows(2) {
+            let (p, q) = (window[0], window[1]);
+            assert_eq!(next_prime(p), Some(q));
+            assert_eq!(prev_prime(q), Some(p));
+        }
+    }
+}
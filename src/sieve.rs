@@ -0,0 +1,106 @@
+//! Bulk prime enumeration via the Sieve of Eratosthenes.
+
+/// A Sieve of Eratosthenes over `0..=limit`.
+///
+/// Building a `Sieve` once and querying it repeatedly is far faster than
+/// calling [`crate::is_prime`] for every candidate when the caller wants all
+/// primes below some bound, since each composite is struck out once instead
+/// of being tested independently.
+///
+/// # Examples
+///
+/// ```
+/// let sieve = yapl::Sieve::up_to(30);
+/// assert!(sieve.is_prime(29));
+/// assert!(!sieve.is_prime(21));
+/// assert_eq!(sieve.count(), 10);
+/// ```
+#[derive(Debug)]
+pub struct Sieve {
+    limit: u64,
+    is_composite: Vec<bool>,
+}
+
+impl Sieve {
+    /// Builds a sieve covering every number from `0` to `limit` inclusive.
+    pub fn up_to(limit: u64) -> Sieve {
+        let mut is_composite = vec![false; limit as usize + 1];
+        if let Some(slot) = is_composite.get_mut(0) {
+            *slot = true;
+        }
+        if let Some(slot) = is_composite.get_mut(1) {
+            *slot = true;
+        }
+
+        let mut i = 2u64;
+        while i * i <= limit {
+            if !is_composite[i as usize] {
+                let mut j = i * i;
+                while j <= limit {
+                    is_composite[j as usize] = true;
+                    j += i;
+                }
+            }
+            i += 1;
+        }
+
+        Sieve { limit, is_composite }
+    }
+
+    /// Returns whether `n` is prime, in `O(1)`.
+    ///
+    /// Returns `false` for any `n` beyond the sieve's `limit`.
+    pub fn is_prime(&self, n: u64) -> bool {
+        if n > self.limit {
+            return false;
+        }
+        !self.is_composite[n as usize]
+    }
+
+    /// Iterates over the primes covered by this sieve, in ascending order.
+    pub fn primes(&self) -> impl Iterator<Item = u64> + '_ {
+        self.is_composite
+            .iter()
+            .enumerate()
+            .filter_map(|(n, &composite)| if composite { None } else { Some(n as u64) })
+    }
+
+    /// Returns `π(limit)`, the number of primes covered by this sieve.
+    pub fn count(&self) -> usize {
+        self.primes().count()
+    }
+
+    /// Returns the `k`th prime covered by this sieve (0-indexed), if any.
+    pub fn nth(&self, k: usize) -> Option<u64> {
+        self.primes().nth(k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_prime() {
+        let sieve = Sieve::up_to(100);
+        for n in 0..=100 {
+            assert_eq!(sieve.is_prime(n), crate::is_prime(n), "mismatch at {n}");
+        }
+        assert!(!sieve.is_prime(101));
+    }
+
+    #[test]
+    fn test_primes_and_count() {
+        let sieve = Sieve::up_to(30);
+        let primes: Vec<u64> = sieve.primes().collect();
+        assert_eq!(primes, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        assert_eq!(sieve.count(), primes.len());
+    }
+
+    #[test]
+    fn test_nth() {
+        let sieve = Sieve::up_to(1_000_000);
+        assert_eq!(sieve.nth(0), Some(2));
+        assert_eq!(sieve.nth(9999), Some(104729));
+    }
+}